@@ -6,24 +6,374 @@ use kos_core::{
     kos_proto::common::{ActionResponse, Error, ErrorCode},
 };
 use linux_bno055::Bno055;
-use std::{sync::Arc, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::sync::Mutex;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+/// Size in bytes of the BNO055 calibration profile (accel/gyro/mag offsets
+/// plus accel/mag radii), per the sensor's register map.
+const CALIBRATION_PROFILE_LEN: usize = 22;
+
+/// Size in bytes of the serialized software calibration (accel scale+offset,
+/// gyro scale+offset: 12 little-endian `f32`s) appended after the BNO055
+/// profile when persisting to disk.
+const SOFTWARE_CALIBRATION_LEN: usize = 48;
+
+/// A per-axis affine correction `corrected = scale * (raw - offset)`, used to
+/// trim residual bias/scale error measured on a bench (e.g. leaving the board
+/// stationary to estimate gyro offset, or tumbling it to fit accel scale).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisCalibration {
+    pub scale: (f32, f32, f32),
+    pub offset: (f32, f32, f32),
+}
+
+impl Default for AxisCalibration {
+    fn default() -> Self {
+        Self {
+            scale: (1.0, 1.0, 1.0),
+            offset: (0.0, 0.0, 0.0),
+        }
+    }
+}
+
+impl AxisCalibration {
+    fn apply(&self, v: (f32, f32, f32)) -> (f32, f32, f32) {
+        (
+            self.scale.0 * (v.0 - self.offset.0),
+            self.scale.1 * (v.1 - self.offset.1),
+            self.scale.2 * (v.2 - self.offset.2),
+        )
+    }
+
+    fn to_bytes(self) -> [u8; 24] {
+        let mut bytes = [0u8; 24];
+        let fields = [
+            self.scale.0,
+            self.scale.1,
+            self.scale.2,
+            self.offset.0,
+            self.offset.1,
+            self.offset.2,
+        ];
+        for (i, f) in fields.into_iter().enumerate() {
+            bytes[i * 4..i * 4 + 4].copy_from_slice(&f.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 24]) -> Self {
+        let f = |i: usize| f32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Self {
+            scale: (f(0), f(1), f(2)),
+            offset: (f(3), f(4), f(5)),
+        }
+    }
+}
+
+/// Software calibration applied to accel/gyro readings on top of the
+/// sensor's own onboard fusion calibration.
+#[derive(Debug, Clone, Copy, Default)]
+struct SoftwareCalibration {
+    accel: AxisCalibration,
+    gyro: AxisCalibration,
+}
+
+impl SoftwareCalibration {
+    fn to_bytes(self) -> [u8; SOFTWARE_CALIBRATION_LEN] {
+        let mut bytes = [0u8; SOFTWARE_CALIBRATION_LEN];
+        bytes[..24].copy_from_slice(&self.accel.to_bytes());
+        bytes[24..].copy_from_slice(&self.gyro.to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; SOFTWARE_CALIBRATION_LEN]) -> Self {
+        Self {
+            accel: AxisCalibration::from_bytes(bytes[..24].try_into().unwrap()),
+            gyro: AxisCalibration::from_bytes(bytes[24..].try_into().unwrap()),
+        }
+    }
+}
+
+/// Fixed mounting rotation of the sensor relative to the robot body frame,
+/// matching how flight firmwares (e.g. ArduPilot's `AHRS_ORIENTATION`) encode
+/// board orientation. Applied to every reading so the same driver code works
+/// whether the board is mounted flat, upside-down, or on its side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImuOrientation {
+    #[default]
+    Identity,
+    RotX180,
+    RotY180,
+    RotZ180,
+    RotX90,
+    RotXNeg90,
+    RotY90,
+    RotYNeg90,
+    RotZ90,
+    RotZNeg90,
+}
+
+impl ImuOrientation {
+    /// Rotation matrix for this orientation, as a const so it's cheap to
+    /// apply per-sample.
+    const fn rotation_matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ImuOrientation::Identity => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            ImuOrientation::RotX180 => [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]],
+            ImuOrientation::RotY180 => [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]],
+            ImuOrientation::RotZ180 => [[-1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]],
+            ImuOrientation::RotX90 => [[1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]],
+            ImuOrientation::RotXNeg90 => [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, -1.0, 0.0]],
+            ImuOrientation::RotY90 => [[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]],
+            ImuOrientation::RotYNeg90 => [[0.0, 0.0, -1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]],
+            ImuOrientation::RotZ90 => [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+            ImuOrientation::RotZNeg90 => [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// The unit quaternion (w, x, y, z) equivalent to `rotation_matrix`.
+    const fn rotation_quaternion(self) -> (f32, f32, f32, f32) {
+        const FRAC_1_SQRT_2: f32 = std::f32::consts::FRAC_1_SQRT_2;
+        match self {
+            ImuOrientation::Identity => (1.0, 0.0, 0.0, 0.0),
+            ImuOrientation::RotX180 => (0.0, 1.0, 0.0, 0.0),
+            ImuOrientation::RotY180 => (0.0, 0.0, 1.0, 0.0),
+            ImuOrientation::RotZ180 => (0.0, 0.0, 0.0, 1.0),
+            ImuOrientation::RotX90 => (FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0, 0.0),
+            ImuOrientation::RotXNeg90 => (FRAC_1_SQRT_2, -FRAC_1_SQRT_2, 0.0, 0.0),
+            ImuOrientation::RotY90 => (FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2, 0.0),
+            ImuOrientation::RotYNeg90 => (FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2, 0.0),
+            ImuOrientation::RotZ90 => (FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2),
+            ImuOrientation::RotZNeg90 => (FRAC_1_SQRT_2, 0.0, 0.0, -FRAC_1_SQRT_2),
+        }
+    }
+
+    /// Rotates a sensor-frame vector (e.g. acceleration or angular velocity)
+    /// into the body frame.
+    fn rotate_vector(self, v: (f32, f32, f32)) -> (f32, f32, f32) {
+        let m = self.rotation_matrix();
+        (
+            m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+            m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+            m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+        )
+    }
+
+    /// Pre-multiplies an orientation quaternion (w, x, y, z) by the mounting
+    /// rotation, rotating it from sensor frame into body frame.
+    fn rotate_quaternion(self, q: (f32, f32, f32, f32)) -> (f32, f32, f32, f32) {
+        let (w1, x1, y1, z1) = self.rotation_quaternion();
+        let (w2, x2, y2, z2) = q;
+        (
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+        )
+    }
+}
+
+/// Converts a unit quaternion (w, x, y, z) into roll/pitch/yaw Euler angles,
+/// in degrees, using the same aerospace ZYX convention as the BNO055's own
+/// Euler output.
+fn quaternion_to_euler(w: f32, x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let sinr_cosp = 2.0 * (w * x + y * z);
+    let cosr_cosp = 1.0 - 2.0 * (x * x + y * y);
+    let roll = sinr_cosp.atan2(cosr_cosp);
+
+    let sinp = 2.0 * (w * y - z * x);
+    let pitch = if sinp.abs() >= 1.0 {
+        std::f32::consts::FRAC_PI_2.copysign(sinp)
+    } else {
+        sinp.asin()
+    };
+
+    let siny_cosp = 2.0 * (w * z + x * y);
+    let cosy_cosp = 1.0 - 2.0 * (y * y + z * z);
+    let yaw = siny_cosp.atan2(cosy_cosp);
+
+    (roll.to_degrees(), pitch.to_degrees(), yaw.to_degrees())
+}
+
+/// Per-subsystem BNO055 calibration levels, each in the range 0 (uncalibrated)
+/// to 3 (fully calibrated).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CalibrationProgress {
+    pub system: u8,
+    pub gyro: u8,
+    pub accel: u8,
+    pub mag: u8,
+}
+
+impl CalibrationProgress {
+    fn is_complete(&self) -> bool {
+        self.system >= 3 && self.gyro >= 3 && self.accel >= 3 && self.mag >= 3
+    }
+}
+
+/// Snapshot of an in-flight (or completed) `calibrate` operation, as
+/// surfaced by `ZBotIMU::get_calibration_status`.
+#[derive(Debug, Clone)]
+pub struct CalibrationStatus {
+    pub operation_name: String,
+    pub done: bool,
+    pub progress: CalibrationProgress,
+}
+
+static NEXT_CALIBRATION_ID: AtomicU64 = AtomicU64::new(0);
+
+const CALIBRATION_TIMEOUT: Duration = Duration::from_secs(60);
+const CALIBRATION_POLL_INTERVAL: Duration = Duration::from_millis(250);
 
 pub struct ZBotIMU {
     imu: Arc<Mutex<Bno055>>,
+    calibration: Arc<Mutex<Option<CalibrationStatus>>>,
+    calibration_profile_path: Option<PathBuf>,
+    orientation: ImuOrientation,
+    software_calibration: Arc<std::sync::RwLock<SoftwareCalibration>>,
 }
 
 impl ZBotIMU {
-    pub fn new(i2c_bus: &str) -> Result<Self> {
-        info!("Initializing ZerothIMU with I2C bus: {}", i2c_bus);
-        
-        let imu = Bno055::new(i2c_bus)?;
-        
+    pub fn new(
+        i2c_bus: &str,
+        calibration_profile_path: Option<PathBuf>,
+        orientation: ImuOrientation,
+    ) -> Result<Self> {
+        info!(
+            "Initializing ZerothIMU with I2C bus: {} (orientation: {:?})",
+            i2c_bus, orientation
+        );
+
+        let mut imu = Bno055::new(i2c_bus)?;
+        let mut software_calibration = SoftwareCalibration::default();
+
+        if let Some(path) = &calibration_profile_path {
+            if path.exists() {
+                match Self::apply_calibration_profile(&mut imu, path) {
+                    Ok(sw_cal) => {
+                        info!("Restored IMU calibration profile from {}", path.display());
+                        if let Some(sw_cal) = sw_cal {
+                            software_calibration = sw_cal;
+                        }
+                    }
+                    Err(e) => warn!(
+                        "Failed to restore IMU calibration profile from {}: {}",
+                        path.display(),
+                        e
+                    ),
+                }
+            }
+        }
+
         Ok(Self {
             imu: Arc::new(Mutex::new(imu)),
+            calibration: Arc::new(Mutex::new(None)),
+            calibration_profile_path,
+            orientation,
+            software_calibration: Arc::new(std::sync::RwLock::new(software_calibration)),
         })
     }
+
+    /// Sets the software calibration (per-axis scale/offset) applied to
+    /// accelerometer and gyroscope readings on top of the sensor's own
+    /// fusion calibration.
+    pub fn set_calibration(&self, accel: AxisCalibration, gyro: AxisCalibration) {
+        let mut cal = self.software_calibration.write().unwrap();
+        cal.accel = accel;
+        cal.gyro = gyro;
+    }
+
+    /// Returns the status (operation name, completion, and per-subsystem
+    /// progress) of the most recently started `calibrate` operation, if one
+    /// has run since startup.
+    pub async fn get_calibration_status(&self) -> Option<CalibrationStatus> {
+        self.calibration.lock().await.clone()
+    }
+
+    /// Reads the sensor's current calibration profile, appends the software
+    /// calibration, and writes the result to `path`.
+    pub async fn save_calibration(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut imu = self.imu.lock().await;
+        let profile = imu.get_calibration_profile()?;
+        drop(imu);
+
+        let mut bytes = Vec::with_capacity(CALIBRATION_PROFILE_LEN + SOFTWARE_CALIBRATION_LEN);
+        bytes.extend_from_slice(&profile);
+        bytes.extend_from_slice(&self.software_calibration.read().unwrap().to_bytes());
+
+        std::fs::write(path, bytes)?;
+        info!("Saved IMU calibration profile to {}", path.display());
+        Ok(())
+    }
+
+    /// Loads a previously saved calibration profile from `path`, applies the
+    /// BNO055 portion to the sensor, and restores the software calibration.
+    pub async fn load_calibration(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut imu = self.imu.lock().await;
+        let sw_cal = Self::apply_calibration_profile(&mut imu, path.as_ref())?;
+        drop(imu);
+
+        if let Some(sw_cal) = sw_cal {
+            *self.software_calibration.write().unwrap() = sw_cal;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a calibration profile from `path` and writes the BNO055 portion
+    /// into the sensor, switching to CONFIG mode for the write and back to
+    /// NDOF afterwards. Returns the trailing software calibration, if the
+    /// file has one (older, sensor-only profiles are accepted for backwards
+    /// compatibility). Warns if the sensor doesn't report full calibration
+    /// once the profile has been applied.
+    fn apply_calibration_profile(
+        imu: &mut Bno055,
+        path: &Path,
+    ) -> Result<Option<SoftwareCalibration>> {
+        let bytes = std::fs::read(path)?;
+        if bytes.len() != CALIBRATION_PROFILE_LEN
+            && bytes.len() != CALIBRATION_PROFILE_LEN + SOFTWARE_CALIBRATION_LEN
+        {
+            eyre::bail!(
+                "calibration profile at {} has unexpected length {} (expected {} or {})",
+                path.display(),
+                bytes.len(),
+                CALIBRATION_PROFILE_LEN,
+                CALIBRATION_PROFILE_LEN + SOFTWARE_CALIBRATION_LEN
+            );
+        }
+
+        let profile: [u8; CALIBRATION_PROFILE_LEN] =
+            bytes[..CALIBRATION_PROFILE_LEN].try_into().unwrap();
+
+        imu.set_mode(linux_bno055::registers::OperationMode::Config)?;
+        imu.set_calibration_profile(&profile)?;
+        imu.set_mode(linux_bno055::registers::OperationMode::Ndof)?;
+
+        let status = imu.get_calibration_status()?;
+        if status.system < 3 || status.gyro < 3 || status.accel < 3 || status.mag < 3 {
+            warn!(
+                "Applied calibration profile from {} but sensor reports incomplete calibration: {:?}",
+                path.display(),
+                status
+            );
+        }
+
+        let sw_cal = (bytes.len() == CALIBRATION_PROFILE_LEN + SOFTWARE_CALIBRATION_LEN).then(
+            || SoftwareCalibration::from_bytes(bytes[CALIBRATION_PROFILE_LEN..].try_into().unwrap()),
+        );
+
+        Ok(sw_cal)
+    }
 }
 
 impl Default for ZBotIMU {
@@ -36,31 +386,84 @@ impl Default for ZBotIMU {
 impl IMU for ZBotIMU {
     async fn get_values(&self) -> Result<ImuValuesResponse> {
         let mut imu = self.imu.lock().await;
-        
-        let accel = imu.get_linear_acceleration()?;
-        
+
+        let accel = imu.get_linear_acceleration();
+        let gyro = imu.get_angular_velocity();
+        let mag = imu.get_magnetometer();
+        drop(imu);
+
+        let cal = *self.software_calibration.read().unwrap();
+        let mut errors: Vec<String> = Vec::new();
+
+        let (accel_x, accel_y, accel_z) = match accel {
+            Ok(a) => {
+                let corrected = cal.accel.apply((a.x, a.y, a.z));
+                self.orientation.rotate_vector(corrected)
+            }
+            Err(e) => {
+                error!("Failed to read IMU acceleration: {}", e);
+                errors.push(format!("acceleration: {}", e));
+                (0.0, 0.0, 0.0)
+            }
+        };
+
+        let (gyro_x, gyro_y, gyro_z) = match gyro {
+            Ok(g) => {
+                let corrected = cal.gyro.apply((g.x, g.y, g.z));
+                self.orientation.rotate_vector(corrected)
+            }
+            Err(e) => {
+                error!("Failed to read IMU gyroscope: {}", e);
+                errors.push(format!("gyroscope: {}", e));
+                (0.0, 0.0, 0.0)
+            }
+        };
+
+        let (mag_x, mag_y, mag_z) = match mag {
+            Ok(m) => {
+                let (x, y, z) = self.orientation.rotate_vector((m.x, m.y, m.z));
+                (Some(x as f64), Some(y as f64), Some(z as f64))
+            }
+            Err(e) => {
+                error!("Failed to read IMU magnetometer: {}", e);
+                errors.push(format!("magnetometer: {}", e));
+                (None, None, None)
+            }
+        };
+
+        let error = (!errors.is_empty()).then(|| Error {
+            code: ErrorCode::HardwareFailure as i32,
+            message: format!("Failed to read IMU group(s): {}", errors.join("; ")),
+        });
+
         Ok(ImuValuesResponse {
-            accel_x: accel.x as f64,
-            accel_y: accel.y as f64,
-            accel_z: accel.z as f64,
-            gyro_x: 0.0, // Note: linux_bno055 doesn't expose raw gyro values in the example
-            gyro_y: 0.0, // You may want to add these if needed
-            gyro_z: 0.0,
-            mag_x: None, // Similarly for magnetometer values
-            mag_y: None,
-            mag_z: None,
-            error: None,
+            accel_x: accel_x as f64,
+            accel_y: accel_y as f64,
+            accel_z: accel_z as f64,
+            gyro_x: gyro_x as f64,
+            gyro_y: gyro_y as f64,
+            gyro_z: gyro_z as f64,
+            mag_x,
+            mag_y,
+            mag_z,
+            error,
         })
     }
 
     async fn get_euler(&self) -> Result<EulerAnglesResponse> {
         let mut imu = self.imu.lock().await;
-        let euler = imu.get_euler_angles()?;
-        
+        let quat = imu.get_quaternion()?;
+        drop(imu);
+
+        let (w, x, y, z) = self
+            .orientation
+            .rotate_quaternion((quat.w, quat.x, quat.y, quat.z));
+        let (roll, pitch, yaw) = quaternion_to_euler(w, x, y, z);
+
         Ok(EulerAnglesResponse {
-            roll: euler.roll as f64,
-            pitch: euler.pitch as f64,
-            yaw: euler.yaw as f64,
+            roll: roll as f64,
+            pitch: pitch as f64,
+            yaw: yaw as f64,
             error: None,
         })
     }
@@ -68,23 +471,129 @@ impl IMU for ZBotIMU {
     async fn get_quaternion(&self) -> Result<QuaternionResponse> {
         let mut imu = self.imu.lock().await;
         let quat = imu.get_quaternion()?;
-        
+        drop(imu);
+
+        let (w, x, y, z) = self
+            .orientation
+            .rotate_quaternion((quat.w, quat.x, quat.y, quat.z));
+
         Ok(QuaternionResponse {
-            w: quat.w as f64,
-            x: quat.x as f64,
-            y: quat.y as f64,
-            z: quat.z as f64,
+            w: w as f64,
+            x: x as f64,
+            y: y as f64,
+            z: z as f64,
             error: None,
         })
     }
 
     async fn calibrate(&self) -> Result<Operation> {
-        info!("Starting IMU calibration");
+        let op_id = NEXT_CALIBRATION_ID.fetch_add(1, Ordering::Relaxed);
+        let name = format!("operations/calibrate_imu/{}", op_id);
+        info!("Starting IMU calibration: {}", name);
+
+        {
+            let mut state = self.calibration.lock().await;
+            *state = Some(CalibrationStatus {
+                operation_name: name.clone(),
+                done: false,
+                progress: CalibrationProgress::default(),
+            });
+        }
+
+        let imu = Arc::clone(&self.imu);
+        let calibration = Arc::clone(&self.calibration);
+        let op_name = name.clone();
+        let profile_path = self.calibration_profile_path.clone();
+        let software_calibration = Arc::clone(&self.software_calibration);
+
+        tokio::spawn(async move {
+            let started = tokio::time::Instant::now();
+
+            loop {
+                if started.elapsed() > CALIBRATION_TIMEOUT {
+                    error!(
+                        "IMU calibration {} timed out waiting for full calibration",
+                        op_name
+                    );
+                    break;
+                }
+
+                let status = imu.lock().await.get_calibration_status();
+                match status {
+                    Ok(status) => {
+                        let progress = CalibrationProgress {
+                            system: status.system,
+                            gyro: status.gyro,
+                            accel: status.accel,
+                            mag: status.mag,
+                        };
+                        let complete = progress.is_complete();
+
+                        {
+                            let mut state = calibration.lock().await;
+                            match state.as_mut() {
+                                Some(s) if s.operation_name == op_name => {
+                                    s.progress = progress;
+                                    s.done = complete;
+                                }
+                                _ => {
+                                    debug!(
+                                        "IMU calibration {} superseded by a newer operation, stopping",
+                                        op_name
+                                    );
+                                    return;
+                                }
+                            }
+                        }
+
+                        if complete {
+                            debug!("IMU calibration {} complete: {:?}", op_name, progress);
+
+                            if let Some(path) = &profile_path {
+                                let mut imu = imu.lock().await;
+                                match imu.get_calibration_profile() {
+                                    Ok(profile) => {
+                                        let mut bytes = Vec::with_capacity(
+                                            CALIBRATION_PROFILE_LEN + SOFTWARE_CALIBRATION_LEN,
+                                        );
+                                        bytes.extend_from_slice(&profile);
+                                        bytes.extend_from_slice(
+                                            &software_calibration.read().unwrap().to_bytes(),
+                                        );
+                                        match std::fs::write(path, bytes) {
+                                            Ok(()) => info!(
+                                                "Persisted IMU calibration profile to {}",
+                                                path.display()
+                                            ),
+                                            Err(e) => error!(
+                                                "Failed to persist IMU calibration profile to {}: {}",
+                                                path.display(),
+                                                e
+                                            ),
+                                        }
+                                    }
+                                    Err(e) => {
+                                        error!("Failed to read back IMU calibration profile: {}", e)
+                                    }
+                                }
+                            }
+
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to read IMU calibration status: {}", e);
+                    }
+                }
+
+                tokio::time::sleep(CALIBRATION_POLL_INTERVAL).await;
+            }
+        });
 
         Ok(Operation {
-            name: "operations/calibrate_imu/0".to_string(),
+            name,
             metadata: None,
-            done: true,
+            done: false,
             result: None,
         })
     }
@@ -131,3 +640,425 @@ impl IMU for ZBotIMU {
         }
     }
 }
+
+/// Health of a single sensor managed by a `ZBotMultiIMU`.
+#[derive(Debug, Clone)]
+pub struct SensorHealth {
+    pub id: String,
+    pub healthy: bool,
+}
+
+/// How a `ZBotMultiIMU` selects among or combines its underlying sensors.
+#[derive(Debug, Clone, Copy)]
+pub enum ImuSelectionPolicy {
+    /// Always read from the sensor at this index.
+    Primary(usize),
+    /// Read from the current sensor; fall over to the next healthy one when
+    /// it returns a hardware error.
+    Failover,
+    /// Average accel/gyro across all healthy sensors, combining quaternions
+    /// via normalized interpolation.
+    Average,
+}
+
+struct ManagedImu {
+    id: String,
+    imu: ZBotIMU,
+}
+
+/// Status of the most recently started multi-sensor `calibrate` operation,
+/// aggregated across every underlying sensor's own `CalibrationStatus`.
+#[derive(Debug, Clone)]
+pub struct MultiCalibrationStatus {
+    pub operation_name: String,
+    pub done: bool,
+    pub progress: CalibrationProgress,
+}
+
+struct MultiCalibrationState {
+    name: String,
+    /// Per-sensor operation name, in the same order as `ZBotMultiIMU::sensors`.
+    /// Empty if that sensor failed to start calibrating.
+    sensor_operations: Vec<String>,
+}
+
+/// Fuses multiple BNO055 sensors (e.g. on different I2C buses, or the two
+/// BNO055 addresses 0x28/0x29) behind a single `IMU` implementation. Each
+/// sub-sensor keeps its own mounting orientation and calibration, so boards
+/// mounted in different orientations can be fused into one body frame.
+pub struct ZBotMultiIMU {
+    sensors: Vec<ManagedImu>,
+    policy: ImuSelectionPolicy,
+    current: Arc<Mutex<usize>>,
+    calibration: Arc<Mutex<Option<MultiCalibrationState>>>,
+}
+
+impl ZBotMultiIMU {
+    pub fn new(sensors: Vec<(String, ZBotIMU)>, policy: ImuSelectionPolicy) -> Result<Self> {
+        if sensors.is_empty() {
+            eyre::bail!("ZBotMultiIMU requires at least one sensor");
+        }
+
+        Ok(Self {
+            sensors: sensors
+                .into_iter()
+                .map(|(id, imu)| ManagedImu { id, imu })
+                .collect(),
+            policy,
+            current: Arc::new(Mutex::new(0)),
+            calibration: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Resolves a configured `Primary(idx)` sensor index, erroring instead of
+    /// silently clamping if it's out of range for this rig.
+    fn resolve_primary(&self, idx: usize) -> Result<usize> {
+        if idx >= self.sensors.len() {
+            eyre::bail!(
+                "ZBotMultiIMU: configured primary sensor index {} is out of range ({} sensors)",
+                idx,
+                self.sensors.len()
+            );
+        }
+        Ok(idx)
+    }
+
+    /// Returns the status of the most recently started multi-sensor
+    /// `calibrate` operation, aggregated across every sensor: done once
+    /// every sensor is done, with combined per-subsystem progress being the
+    /// minimum (worst) level reported by any sensor.
+    pub async fn get_calibration_status(&self) -> Option<MultiCalibrationStatus> {
+        let state = self.calibration.lock().await;
+        let state = state.as_ref()?;
+
+        let mut done = true;
+        let mut progress = CalibrationProgress {
+            system: 3,
+            gyro: 3,
+            accel: 3,
+            mag: 3,
+        };
+
+        for (sensor, op_name) in self.sensors.iter().zip(state.sensor_operations.iter()) {
+            if op_name.is_empty() {
+                done = false;
+                continue;
+            }
+
+            match sensor.imu.get_calibration_status().await {
+                Some(status) if status.operation_name == *op_name => {
+                    done &= status.done;
+                    progress.system = progress.system.min(status.progress.system);
+                    progress.gyro = progress.gyro.min(status.progress.gyro);
+                    progress.accel = progress.accel.min(status.progress.accel);
+                    progress.mag = progress.mag.min(status.progress.mag);
+                }
+                _ => done = false,
+            }
+        }
+
+        Some(MultiCalibrationStatus {
+            operation_name: state.name.clone(),
+            done,
+            progress,
+        })
+    }
+
+    /// Reports which underlying sensors are currently responding.
+    pub async fn sensor_health(&self) -> Vec<SensorHealth> {
+        let mut health = Vec::with_capacity(self.sensors.len());
+        for sensor in &self.sensors {
+            let healthy = sensor.imu.get_euler().await.is_ok();
+            health.push(SensorHealth {
+                id: sensor.id.clone(),
+                healthy,
+            });
+        }
+        health
+    }
+
+    async fn get_values_failover(&self) -> Result<ImuValuesResponse> {
+        let mut last_err = None;
+        let mut last_partial = None;
+
+        for _ in 0..self.sensors.len() {
+            let idx = *self.current.lock().await;
+            match self.sensors[idx].imu.get_values().await {
+                Ok(values) if values.error.is_none() => return Ok(values),
+                Ok(values) => {
+                    error!(
+                        "IMU '{}' reported a hardware error, failing over: {:?}",
+                        self.sensors[idx].id, values.error
+                    );
+                    last_partial = Some(values);
+                    *self.current.lock().await = (idx + 1) % self.sensors.len();
+                }
+                Err(e) => {
+                    error!("IMU '{}' failed, failing over: {}", self.sensors[idx].id, e);
+                    last_err = Some(e);
+                    *self.current.lock().await = (idx + 1) % self.sensors.len();
+                }
+            }
+        }
+
+        if let Some(values) = last_partial {
+            return Ok(values);
+        }
+
+        Err(last_err.unwrap_or_else(|| eyre::eyre!("no sensors available")))
+    }
+
+    async fn get_euler_failover(&self) -> Result<EulerAnglesResponse> {
+        let mut last_err = None;
+
+        for _ in 0..self.sensors.len() {
+            let idx = *self.current.lock().await;
+            match self.sensors[idx].imu.get_euler().await {
+                Ok(euler) => return Ok(euler),
+                Err(e) => {
+                    error!("IMU '{}' failed, failing over: {}", self.sensors[idx].id, e);
+                    last_err = Some(e);
+                    *self.current.lock().await = (idx + 1) % self.sensors.len();
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| eyre::eyre!("no sensors available")))
+    }
+
+    async fn get_quaternion_failover(&self) -> Result<QuaternionResponse> {
+        let mut last_err = None;
+
+        for _ in 0..self.sensors.len() {
+            let idx = *self.current.lock().await;
+            match self.sensors[idx].imu.get_quaternion().await {
+                Ok(quat) => return Ok(quat),
+                Err(e) => {
+                    error!("IMU '{}' failed, failing over: {}", self.sensors[idx].id, e);
+                    last_err = Some(e);
+                    *self.current.lock().await = (idx + 1) % self.sensors.len();
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| eyre::eyre!("no sensors available")))
+    }
+
+    async fn get_values_average(&self) -> Result<ImuValuesResponse> {
+        let mut sum_accel = (0.0f64, 0.0f64, 0.0f64);
+        let mut sum_gyro = (0.0f64, 0.0f64, 0.0f64);
+        let mut n = 0u32;
+
+        for sensor in &self.sensors {
+            match sensor.imu.get_values().await {
+                Ok(v) if v.error.is_some() => debug!(
+                    "IMU '{}' reported a hardware error, excluded from averaging: {:?}",
+                    sensor.id, v.error
+                ),
+                Ok(v) => {
+                    sum_accel.0 += v.accel_x;
+                    sum_accel.1 += v.accel_y;
+                    sum_accel.2 += v.accel_z;
+                    sum_gyro.0 += v.gyro_x;
+                    sum_gyro.1 += v.gyro_y;
+                    sum_gyro.2 += v.gyro_z;
+                    n += 1;
+                }
+                Err(e) => debug!("IMU '{}' unavailable for averaging: {}", sensor.id, e),
+            }
+        }
+
+        if n == 0 {
+            eyre::bail!("no healthy IMU sensors available to average");
+        }
+
+        let n = f64::from(n);
+        Ok(ImuValuesResponse {
+            accel_x: sum_accel.0 / n,
+            accel_y: sum_accel.1 / n,
+            accel_z: sum_accel.2 / n,
+            gyro_x: sum_gyro.0 / n,
+            gyro_y: sum_gyro.1 / n,
+            gyro_z: sum_gyro.2 / n,
+            mag_x: None,
+            mag_y: None,
+            mag_z: None,
+            error: None,
+        })
+    }
+
+    async fn get_quaternion_average(&self) -> Result<QuaternionResponse> {
+        let mut sum = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+        let mut reference: Option<(f64, f64, f64, f64)> = None;
+        let mut n = 0u32;
+
+        for sensor in &self.sensors {
+            match sensor.imu.get_quaternion().await {
+                Ok(q) => {
+                    let mut sample = (q.w, q.x, q.y, q.z);
+
+                    // q and -q represent the same rotation, but the BNO055's
+                    // fusion output sign isn't guaranteed consistent across
+                    // independent sensors. Flip antipodal samples onto the
+                    // same hemisphere as the first healthy reading so they
+                    // reinforce instead of cancelling out.
+                    match reference {
+                        Some(r) => {
+                            let dot = r.0 * sample.0 + r.1 * sample.1 + r.2 * sample.2 + r.3 * sample.3;
+                            if dot < 0.0 {
+                                sample = (-sample.0, -sample.1, -sample.2, -sample.3);
+                            }
+                        }
+                        None => reference = Some(sample),
+                    }
+
+                    sum.0 += sample.0;
+                    sum.1 += sample.1;
+                    sum.2 += sample.2;
+                    sum.3 += sample.3;
+                    n += 1;
+                }
+                Err(e) => debug!("IMU '{}' unavailable for averaging: {}", sensor.id, e),
+            }
+        }
+
+        if n == 0 {
+            eyre::bail!("no healthy IMU sensors available to average");
+        }
+
+        let norm = (sum.0 * sum.0 + sum.1 * sum.1 + sum.2 * sum.2 + sum.3 * sum.3).sqrt();
+        if norm == 0.0 {
+            eyre::bail!("averaged IMU quaternion degenerated to zero");
+        }
+
+        Ok(QuaternionResponse {
+            w: sum.0 / norm,
+            x: sum.1 / norm,
+            y: sum.2 / norm,
+            z: sum.3 / norm,
+            error: None,
+        })
+    }
+
+    async fn get_euler_average(&self) -> Result<EulerAnglesResponse> {
+        let quat = self.get_quaternion_average().await?;
+        let (roll, pitch, yaw) = quaternion_to_euler(
+            quat.w as f32,
+            quat.x as f32,
+            quat.y as f32,
+            quat.z as f32,
+        );
+
+        Ok(EulerAnglesResponse {
+            roll: roll as f64,
+            pitch: pitch as f64,
+            yaw: yaw as f64,
+            error: None,
+        })
+    }
+}
+
+impl Default for ZBotMultiIMU {
+    fn default() -> Self {
+        unimplemented!("ZBotMultiIMU cannot be default, it requires sensor configuration")
+    }
+}
+
+#[async_trait]
+impl IMU for ZBotMultiIMU {
+    async fn get_values(&self) -> Result<ImuValuesResponse> {
+        match self.policy {
+            ImuSelectionPolicy::Primary(idx) => {
+                let idx = self.resolve_primary(idx)?;
+                self.sensors[idx].imu.get_values().await
+            }
+            ImuSelectionPolicy::Failover => self.get_values_failover().await,
+            ImuSelectionPolicy::Average => self.get_values_average().await,
+        }
+    }
+
+    async fn get_euler(&self) -> Result<EulerAnglesResponse> {
+        match self.policy {
+            ImuSelectionPolicy::Primary(idx) => {
+                let idx = self.resolve_primary(idx)?;
+                self.sensors[idx].imu.get_euler().await
+            }
+            ImuSelectionPolicy::Failover => self.get_euler_failover().await,
+            ImuSelectionPolicy::Average => self.get_euler_average().await,
+        }
+    }
+
+    async fn get_quaternion(&self) -> Result<QuaternionResponse> {
+        match self.policy {
+            ImuSelectionPolicy::Primary(idx) => {
+                let idx = self.resolve_primary(idx)?;
+                self.sensors[idx].imu.get_quaternion().await
+            }
+            ImuSelectionPolicy::Failover => self.get_quaternion_failover().await,
+            ImuSelectionPolicy::Average => self.get_quaternion_average().await,
+        }
+    }
+
+    async fn calibrate(&self) -> Result<Operation> {
+        let op_id = NEXT_CALIBRATION_ID.fetch_add(1, Ordering::Relaxed);
+        let name = format!("operations/calibrate_imu_multi/{}", op_id);
+        info!(
+            "Starting IMU calibration across {} sensors: {}",
+            self.sensors.len(),
+            name
+        );
+
+        let mut sensor_operations = Vec::with_capacity(self.sensors.len());
+        for sensor in &self.sensors {
+            match sensor.imu.calibrate().await {
+                Ok(op) => sensor_operations.push(op.name),
+                Err(e) => {
+                    error!("Failed to start calibration for IMU '{}': {}", sensor.id, e);
+                    sensor_operations.push(String::new());
+                }
+            }
+        }
+
+        *self.calibration.lock().await = Some(MultiCalibrationState {
+            name: name.clone(),
+            sensor_operations,
+        });
+
+        Ok(Operation {
+            name,
+            metadata: None,
+            done: false,
+            result: None,
+        })
+    }
+
+    async fn zero(
+        &self,
+        duration: Option<Duration>,
+        max_retries: Option<u32>,
+        max_angular_error: Option<f32>,
+        max_vel: Option<f32>,
+        max_accel: Option<f32>,
+    ) -> Result<ActionResponse> {
+        let mut all_ok = true;
+        let mut last_error = None;
+
+        for sensor in &self.sensors {
+            let response = sensor
+                .imu
+                .zero(duration, max_retries, max_angular_error, max_vel, max_accel)
+                .await?;
+
+            if !response.success {
+                error!("Failed to zero IMU '{}'", sensor.id);
+                all_ok = false;
+                last_error = response.error;
+            }
+        }
+
+        Ok(ActionResponse {
+            success: all_ok,
+            error: if all_ok { None } else { last_error },
+        })
+    }
+}